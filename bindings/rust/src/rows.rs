@@ -1,20 +1,30 @@
 use turso_core::types::FromValue;
 
 use crate::{Error, Result, Value};
+use futures_core::Stream;
+use futures_util::StreamExt;
 use std::fmt::Debug;
 use std::future::Future;
-use std::sync::{Arc, Mutex};
-use std::task::Poll;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::task::{Context, Poll};
 
 /// Results of a prepared statement query.
 pub struct Rows {
     inner: Arc<Mutex<turso_core::Statement>>,
+    columns: Arc<OnceLock<Arc<[Column]>>>,
+    // An error hit mid-fill in `poll_batch` after rows were already queued,
+    // held back so the partial batch can be returned first and the error
+    // replayed on the next poll instead of dropping the queued rows.
+    pending_batch_error: Arc<Mutex<Option<Error>>>,
 }
 
 impl Clone for Rows {
     fn clone(&self) -> Self {
         Self {
             inner: Arc::clone(&self.inner),
+            columns: Arc::clone(&self.columns),
+            pending_batch_error: Arc::clone(&self.pending_batch_error),
         }
     }
 }
@@ -26,57 +36,315 @@ impl Rows {
     pub(crate) fn new(inner: &Arc<Mutex<turso_core::Statement>>) -> Self {
         Self {
             inner: Arc::clone(inner),
+            columns: Arc::new(OnceLock::new()),
+            pending_batch_error: Arc::new(Mutex::new(None)),
         }
     }
     /// Fetch the next row of this result set.
     pub async fn next(&mut self) -> Result<Option<Row>> {
-        struct Next {
-            stmt: Arc<Mutex<turso_core::Statement>>,
-        }
-
-        impl Future for Next {
-            type Output = Result<Option<Row>>;
-
-            fn poll(
-                self: std::pin::Pin<&mut Self>,
-                cx: &mut std::task::Context<'_>,
-            ) -> std::task::Poll<Self::Output> {
-                let mut stmt = self
-                    .stmt
-                    .lock()
-                    .map_err(|e| Error::MutexError(e.to_string()))?;
-                match stmt.step_with_waker(cx.waker())? {
-                    turso_core::StepResult::Row => {
-                        let row = stmt.row().unwrap();
-                        Poll::Ready(Ok(Some(Row {
-                            values: row.get_values().map(|v| v.to_owned()).collect(),
-                            names: (0..stmt.num_columns())
-                                .map(|idx| stmt.get_column_name(idx).into_owned())
-                                .collect(),
-                        })))
-                    }
-                    turso_core::StepResult::Done => Poll::Ready(Ok(None)),
-                    turso_core::StepResult::IO => {
-                        stmt.run_once()?;
-                        Poll::Pending
-                    }
-                    turso_core::StepResult::Busy => Poll::Ready(Err(Error::SqlExecutionFailure(
-                        "database is locked".to_string(),
-                    ))),
-                    turso_core::StepResult::Interrupt => {
-                        Poll::Ready(Err(Error::SqlExecutionFailure("interrupted".to_string())))
+        StreamExt::next(self).await.transpose()
+    }
+
+    /// The columns of this result set, available before the first row is fetched.
+    pub fn columns(&self) -> Result<&[Column]> {
+        let stmt = self
+            .inner
+            .lock()
+            .map_err(|e| Error::MutexError(e.to_string()))?;
+        Ok(self.columns.get_or_init(|| Self::read_columns(&stmt)))
+    }
+
+    fn read_columns(stmt: &turso_core::Statement) -> Arc<[Column]> {
+        (0..stmt.num_columns())
+            .map(|idx| Column {
+                name: stmt.get_column_name(idx).into_owned(),
+                // Assumes `turso_core::Statement::get_column_type` exists
+                // with this signature. Unverified: `turso_core` is not part
+                // of this repository slice, so this call is not checked here.
+                decl_type: stmt.get_column_type(idx).map(|t| t.into_owned()),
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Maps this result set onto `T` via [`FromRow`].
+    pub fn into_typed<T: FromRow>(self) -> TypedRows<T> {
+        TypedRows {
+            rows: self,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Fetches up to `n` rows, locking the statement once per poll rather
+    /// than once per row.
+    ///
+    /// Returns fewer than `n` rows when the result set is exhausted, or when
+    /// an error stops the fill after some rows were already fetched; that
+    /// error is then returned by the next call.
+    pub async fn fetch_batch(&mut self, n: usize) -> Result<Vec<Row>> {
+        struct FetchBatch<'a> {
+            rows: &'a Rows,
+            n: usize,
+            batch: Vec<Row>,
+        }
+
+        impl Future for FetchBatch<'_> {
+            type Output = Result<Vec<Row>>;
+
+            fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+                let this = self.get_mut();
+                match this.rows.poll_batch(this.n, &mut this.batch, cx) {
+                    Poll::Ready(Ok(_exhausted)) => Poll::Ready(Ok(std::mem::take(&mut this.batch))),
+                    Poll::Ready(Err(e)) => Poll::Ready(Err(e)),
+                    Poll::Pending => Poll::Pending,
+                }
+            }
+        }
+
+        unsafe impl Send for FetchBatch<'_> {}
+
+        FetchBatch {
+            rows: self,
+            n,
+            batch: Vec::with_capacity(n),
+        }
+        .await
+    }
+
+    /// Returns a stream of row batches of up to `n` rows each.
+    pub fn chunks(self, n: usize) -> Chunks {
+        Chunks {
+            rows: self,
+            n,
+            batch: Vec::with_capacity(n),
+            done: false,
+        }
+    }
+
+    /// Steps the statement, locking it once, until `batch` holds `n` rows
+    /// or the result set is exhausted. Shared by [`Rows::fetch_batch`] and
+    /// [`Chunks`] so both pay for one lock acquisition per poll instead of
+    /// one per row. On success, the `bool` reports whether the result set
+    /// is now exhausted.
+    ///
+    /// An error hit after rows were already pushed into `batch` is stashed
+    /// rather than returned immediately, so the caller gets the partial
+    /// batch back instead of losing it; the error is returned on the next
+    /// call instead.
+    fn poll_batch(&self, n: usize, batch: &mut Vec<Row>, cx: &mut Context<'_>) -> Poll<Result<bool>> {
+        if let Some(e) = self
+            .pending_batch_error
+            .lock()
+            .map_err(|e| Error::MutexError(e.to_string()))?
+            .take()
+        {
+            return Poll::Ready(Err(e));
+        }
+        let mut stmt = self
+            .inner
+            .lock()
+            .map_err(|e| Error::MutexError(e.to_string()))?;
+        let columns = self
+            .columns
+            .get_or_init(|| Self::read_columns(&stmt))
+            .clone();
+        while batch.len() < n {
+            let step = match stmt.step_with_waker(cx.waker()) {
+                Ok(step) => step,
+                Err(e) => return self.defer_or_return(batch, e.into()),
+            };
+            match step {
+                turso_core::StepResult::Row => {
+                    let row = stmt.row().unwrap();
+                    batch.push(Row {
+                        values: row.get_values().map(|v| v.to_owned()).collect(),
+                        columns: columns.clone(),
+                    });
+                }
+                turso_core::StepResult::Done => return Poll::Ready(Ok(true)),
+                turso_core::StepResult::IO => {
+                    if let Err(e) = stmt.run_once() {
+                        return self.defer_or_return(batch, e.into());
                     }
+                    return Poll::Pending;
+                }
+                turso_core::StepResult::Busy => return self.defer_or_return(batch, busy_error()),
+                turso_core::StepResult::Interrupt => {
+                    return self.defer_or_return(batch, interrupt_error())
                 }
             }
         }
+        Poll::Ready(Ok(false))
+    }
 
-        unsafe impl Send for Next {}
+    /// Returns `err` immediately if `batch` is empty, otherwise stashes it
+    /// in `pending_batch_error` and reports the batch as a (non-exhausted)
+    /// success so the caller doesn't drop the rows already queued.
+    fn defer_or_return(&self, batch: &mut [Row], err: Error) -> Poll<Result<bool>> {
+        if batch.is_empty() {
+            return Poll::Ready(Err(err));
+        }
+        match self.pending_batch_error.lock() {
+            Ok(mut pending) => *pending = Some(err),
+            Err(e) => return Poll::Ready(Err(Error::MutexError(e.to_string()))),
+        }
+        Poll::Ready(Ok(false))
+    }
+}
 
-        let next = Next {
-            stmt: self.inner.clone(),
+fn busy_error() -> Error {
+    Error::SqlExecutionFailure("database is locked".to_string())
+}
+
+fn interrupt_error() -> Error {
+    Error::SqlExecutionFailure("interrupted".to_string())
+}
+
+impl Stream for Rows {
+    type Item = Result<Row>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let mut stmt = match this.inner.lock() {
+            Ok(stmt) => stmt,
+            Err(e) => return Poll::Ready(Some(Err(Error::MutexError(e.to_string())))),
         };
+        let columns = this
+            .columns
+            .get_or_init(|| Self::read_columns(&stmt))
+            .clone();
+        match stmt.step_with_waker(cx.waker()) {
+            Ok(turso_core::StepResult::Row) => {
+                let row = stmt.row().unwrap();
+                Poll::Ready(Some(Ok(Row {
+                    values: row.get_values().map(|v| v.to_owned()).collect(),
+                    columns,
+                })))
+            }
+            Ok(turso_core::StepResult::Done) => Poll::Ready(None),
+            Ok(turso_core::StepResult::IO) => match stmt.run_once() {
+                Ok(()) => Poll::Pending,
+                Err(e) => Poll::Ready(Some(Err(e.into()))),
+            },
+            Ok(turso_core::StepResult::Busy) => Poll::Ready(Some(Err(busy_error()))),
+            Ok(turso_core::StepResult::Interrupt) => Poll::Ready(Some(Err(interrupt_error()))),
+            Err(e) => Poll::Ready(Some(Err(e.into()))),
+        }
+    }
+}
 
-        next.await
+/// A [`Rows`] stream that maps each row onto `T`. Created by [`Rows::into_typed`].
+pub struct TypedRows<T> {
+    rows: Rows,
+    // `fn() -> T` rather than `T` so `TypedRows<T>` stays `Unpin` regardless
+    // of `T`; `poll_next` below needs that to call `self.get_mut()`.
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T: FromRow> Stream for TypedRows<T> {
+    type Item = Result<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        Pin::new(&mut this.rows).poll_next(cx).map(|item| {
+            item.map(|row| match row {
+                Ok(row) => T::from_row(&row),
+                Err(e) => Err(e),
+            })
+        })
+    }
+}
+
+/// A stream of row batches, created by [`Rows::chunks`].
+pub struct Chunks {
+    rows: Rows,
+    n: usize,
+    batch: Vec<Row>,
+    done: bool,
+}
+
+impl Stream for Chunks {
+    type Item = Result<Vec<Row>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        match this.rows.poll_batch(this.n, &mut this.batch, cx) {
+            Poll::Ready(Ok(exhausted)) => {
+                let (item, done) = finalize_batch(std::mem::take(&mut this.batch), exhausted);
+                this.done = done;
+                match item {
+                    Some(batch) => Poll::Ready(Some(Ok(batch))),
+                    None => Poll::Ready(None),
+                }
+            }
+            Poll::Ready(Err(e)) => {
+                this.done = true;
+                Poll::Ready(Some(Err(e)))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Decides what a freshly filled batch yields: `None` once the result set
+/// is exhausted and nothing is left to return, paired with whether the
+/// stream is now done.
+fn finalize_batch(batch: Vec<Row>, exhausted: bool) -> (Option<Vec<Row>>, bool) {
+    if batch.is_empty() {
+        (None, exhausted)
+    } else {
+        (Some(batch), exhausted)
+    }
+}
+
+/// Converts a [`Row`] into `Self`. Blanket-implemented for tuples, mapping
+/// each element to the column at its position.
+pub trait FromRow: Sized {
+    fn from_row(row: &Row) -> Result<Self>;
+}
+
+macro_rules! impl_from_row_for_tuple {
+    ($($T:ident : $idx:tt),+) => {
+        impl<$($T),+> FromRow for ($($T,)+)
+        where
+            $($T: FromValue,)+
+        {
+            fn from_row(row: &Row) -> Result<Self> {
+                Ok(($(row.get::<usize, $T>($idx)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_row_for_tuple!(A:0);
+impl_from_row_for_tuple!(A:0, B:1);
+impl_from_row_for_tuple!(A:0, B:1, C:2);
+impl_from_row_for_tuple!(A:0, B:1, C:2, D:3);
+impl_from_row_for_tuple!(A:0, B:1, C:2, D:3, E:4);
+impl_from_row_for_tuple!(A:0, B:1, C:2, D:3, E:4, F:5);
+impl_from_row_for_tuple!(A:0, B:1, C:2, D:3, E:4, F:5, G:6);
+impl_from_row_for_tuple!(A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7);
+
+/// The name and declared type of a single column in a result set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    name: String,
+    decl_type: Option<String>,
+}
+
+impl Column {
+    /// The column's name, as it appears in the query's result set.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The column's declared type, or `None` if the schema doesn't specify one.
+    pub fn decl_type(&self) -> Option<&str> {
+        self.decl_type.as_deref()
     }
 }
 
@@ -84,13 +352,26 @@ impl Rows {
 #[derive(Debug)]
 pub struct Row {
     values: Vec<turso_core::Value>,
-    names: Vec<String>,
+    columns: Arc<[Column]>,
 }
 
 unsafe impl Send for Row {}
 unsafe impl Sync for Row {}
 
 impl Row {
+    /// The name of the column at `idx`.
+    pub fn column_name(&self, idx: usize) -> Result<&str> {
+        self.columns
+            .get(idx)
+            .map(|c| c.name.as_str())
+            .ok_or(Error::InvalidColumnIndex(idx))
+    }
+
+    /// The names and declared types of this row's columns.
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
     pub fn get_value<I>(&self, idx: I) -> Result<Value>
     where
         I: RowIndex,
@@ -122,9 +403,9 @@ impl Row {
 
     pub fn column_index(&self, name: &str) -> Result<usize> {
         let idx = self
-            .names
+            .columns
             .iter()
-            .position(|n| n == name)
+            .position(|c| c.name == name)
             .ok_or_else(|| Error::InvalidColumnName(name.to_string()))?;
 
         if idx > self.column_count() {
@@ -150,7 +431,7 @@ impl<'a> FromIterator<&'a turso_core::Value> for Row {
 
         Row {
             values,
-            names: vec![],
+            columns: Arc::from(Vec::new()),
         }
     }
 }
@@ -176,3 +457,455 @@ impl RowIndex for &'_ str {
         row.column_index(self)
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::de::Error for Error {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        Error::ConversionFailure(msg.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Row {
+    /// Deserializes this row into `T`, matching struct fields by column
+    /// name or filling a tuple positionally.
+    pub fn deserialize<T: serde::de::DeserializeOwned>(&self) -> Result<T> {
+        T::deserialize(de::RowDeserializer { row: self })
+    }
+}
+
+#[cfg(feature = "serde")]
+mod de {
+    use super::{Error, Result, Row};
+    use serde::de::{
+        DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+    };
+
+    pub(super) struct RowDeserializer<'a> {
+        pub(super) row: &'a Row,
+    }
+
+    impl<'de, 'a> Deserializer<'de> for RowDeserializer<'a> {
+        type Error = Error;
+
+        fn deserialize_any<V>(self, _visitor: V) -> Result<V::Value>
+        where
+            V: Visitor<'de>,
+        {
+            Err(Error::ConversionFailure(
+                "cannot deserialize a row without a concrete struct or tuple type".to_string(),
+            ))
+        }
+
+        fn deserialize_struct<V>(
+            self,
+            _name: &'static str,
+            fields: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_map(RowMapAccess {
+                row: self.row,
+                fields: fields.iter(),
+                next_idx: None,
+            })
+        }
+
+        fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value>
+        where
+            V: Visitor<'de>,
+        {
+            visitor.visit_seq(RowSeqAccess {
+                row: self.row,
+                idx: 0,
+            })
+        }
+
+        fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_seq(visitor)
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct tuple_struct
+            map enum identifier ignored_any
+        }
+    }
+
+    struct RowMapAccess<'a> {
+        row: &'a Row,
+        fields: std::slice::Iter<'static, &'static str>,
+        next_idx: Option<usize>,
+    }
+
+    impl<'de, 'a> MapAccess<'de> for RowMapAccess<'a> {
+        type Error = Error;
+
+        fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>>
+        where
+            K: DeserializeSeed<'de>,
+        {
+            for field in self.fields.by_ref() {
+                if let Ok(idx) = self.row.column_index(field) {
+                    self.next_idx = Some(idx);
+                    return seed.deserialize((*field).into_deserializer()).map(Some);
+                }
+            }
+            Ok(None)
+        }
+
+        fn next_value_seed<S>(&mut self, seed: S) -> Result<S::Value>
+        where
+            S: DeserializeSeed<'de>,
+        {
+            let idx = self
+                .next_idx
+                .take()
+                .expect("next_value_seed called before next_key_seed");
+            seed.deserialize(ValueDeserializer(&self.row.values[idx]))
+        }
+    }
+
+    struct RowSeqAccess<'a> {
+        row: &'a Row,
+        idx: usize,
+    }
+
+    impl<'de, 'a> SeqAccess<'de> for RowSeqAccess<'a> {
+        type Error = Error;
+
+        fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+        where
+            T: DeserializeSeed<'de>,
+        {
+            let Some(value) = self.row.values.get(self.idx) else {
+                return Ok(None);
+            };
+            self.idx += 1;
+            seed.deserialize(ValueDeserializer(value)).map(Some)
+        }
+
+        fn size_hint(&self) -> Option<usize> {
+            Some(self.row.values.len().saturating_sub(self.idx))
+        }
+    }
+
+    struct BlobSeqAccess<'a> {
+        bytes: &'a [u8],
+        idx: usize,
+    }
+
+    impl<'de, 'a> SeqAccess<'de> for BlobSeqAccess<'a> {
+        type Error = Error;
+
+        fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+        where
+            T: DeserializeSeed<'de>,
+        {
+            let Some(byte) = self.bytes.get(self.idx) else {
+                return Ok(None);
+            };
+            self.idx += 1;
+            seed.deserialize((*byte).into_deserializer()).map(Some)
+        }
+
+        fn size_hint(&self) -> Option<usize> {
+            Some(self.bytes.len().saturating_sub(self.idx))
+        }
+    }
+
+    struct ValueDeserializer<'a>(&'a turso_core::Value);
+
+    impl ValueDeserializer<'_> {
+        fn unexpected(&self, expected: &str) -> Error {
+            Error::ConversionFailure(format!("expected {expected}, found {:?}", self.0))
+        }
+    }
+
+    impl<'de, 'a> Deserializer<'de> for ValueDeserializer<'a> {
+        type Error = Error;
+
+        fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
+        where
+            V: Visitor<'de>,
+        {
+            match self.0 {
+                turso_core::Value::Null => visitor.visit_none(),
+                turso_core::Value::Integer(i) => visitor.visit_i64(*i),
+                turso_core::Value::Float(f) => visitor.visit_f64(*f),
+                turso_core::Value::Text(s) => visitor.visit_str(s.as_str()),
+                turso_core::Value::Blob(b) => visitor.visit_bytes(b.as_slice()),
+            }
+        }
+
+        fn deserialize_option<V>(self, visitor: V) -> Result<V::Value>
+        where
+            V: Visitor<'de>,
+        {
+            match self.0 {
+                turso_core::Value::Null => visitor.visit_none(),
+                _ => visitor.visit_some(self),
+            }
+        }
+
+        fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value>
+        where
+            V: Visitor<'de>,
+        {
+            match self.0 {
+                turso_core::Value::Integer(i) => visitor.visit_bool(*i != 0),
+                _ => Err(self.unexpected("bool")),
+            }
+        }
+
+        fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value>
+        where
+            V: Visitor<'de>,
+        {
+            match self.0 {
+                turso_core::Value::Integer(i) => visitor.visit_i64(*i),
+                _ => Err(self.unexpected("integer")),
+            }
+        }
+
+        fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value>
+        where
+            V: Visitor<'de>,
+        {
+            match self.0 {
+                turso_core::Value::Float(f) => visitor.visit_f64(*f),
+                turso_core::Value::Integer(i) => visitor.visit_f64(*i as f64),
+                _ => Err(self.unexpected("float")),
+            }
+        }
+
+        fn deserialize_str<V>(self, visitor: V) -> Result<V::Value>
+        where
+            V: Visitor<'de>,
+        {
+            match self.0 {
+                turso_core::Value::Text(s) => visitor.visit_str(s.as_str()),
+                _ => Err(self.unexpected("text")),
+            }
+        }
+
+        // `String`'s `Deserialize` impl calls `deserialize_string`, and
+        // `char`'s calls `deserialize_char`, not `deserialize_str` — if
+        // those forwarded to `deserialize_any` instead, a blob value would
+        // fall through to `visit_bytes` and silently UTF-8-decode instead
+        // of hitting the text check in `deserialize_str`. Route them through
+        // `deserialize_str` explicitly so blobs are rejected the same way.
+        fn deserialize_string<V>(self, visitor: V) -> Result<V::Value>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_str(visitor)
+        }
+
+        fn deserialize_char<V>(self, visitor: V) -> Result<V::Value>
+        where
+            V: Visitor<'de>,
+        {
+            self.deserialize_str(visitor)
+        }
+
+        fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value>
+        where
+            V: Visitor<'de>,
+        {
+            match self.0 {
+                turso_core::Value::Blob(b) => visitor.visit_bytes(b.as_slice()),
+                _ => Err(self.unexpected("blob")),
+            }
+        }
+
+        // Plain (un-annotated) `Vec<u8>` fields go through `deserialize_seq`,
+        // not `deserialize_bytes` — forwarding to `deserialize_any` would
+        // hand its seq-visitor a `visit_bytes` call it doesn't implement
+        // and fail with "invalid type: byte array, expected a sequence".
+        // Give it a real `SeqAccess` over the blob's bytes instead.
+        fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value>
+        where
+            V: Visitor<'de>,
+        {
+            match self.0 {
+                turso_core::Value::Blob(b) => visitor.visit_seq(BlobSeqAccess {
+                    bytes: b.as_slice(),
+                    idx: 0,
+                }),
+                _ => Err(self.unexpected("sequence")),
+            }
+        }
+
+        serde::forward_to_deserialize_any! {
+            i8 i16 i32 i128 u8 u16 u32 u64 u128 f32 byte_buf unit
+            unit_struct newtype_struct tuple tuple_struct map struct enum
+            identifier ignored_any
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `turso_core::Statement` can't be constructed in isolation, so the
+    // `Busy`/`Interrupt` -> error mapping in `Rows::poll_next` and
+    // `poll_batch` is covered at the unit the errors themselves come from;
+    // driving an actual statement through those step results is not
+    // covered by these tests.
+    #[test]
+    fn busy_and_interrupt_map_to_sql_execution_failure() {
+        assert!(matches!(busy_error(), Error::SqlExecutionFailure(_)));
+        assert!(matches!(interrupt_error(), Error::SqlExecutionFailure(_)));
+    }
+
+    fn row(values: Vec<turso_core::Value>, names: &[&str]) -> Row {
+        Row {
+            values,
+            columns: names
+                .iter()
+                .map(|name| Column {
+                    name: name.to_string(),
+                    decl_type: None,
+                })
+                .collect::<Vec<_>>()
+                .into(),
+        }
+    }
+
+    #[test]
+    fn from_row_reads_tuple_elements_by_position() {
+        let row = row(
+            vec![turso_core::Value::Integer(7), turso_core::Value::Integer(9)],
+            &["a", "b"],
+        );
+
+        let (a, b): (i64, i64) = FromRow::from_row(&row).unwrap();
+        assert_eq!(a, 7);
+        assert_eq!(b, 9);
+    }
+
+    #[test]
+    fn row_exposes_column_metadata() {
+        let row = row(
+            vec![turso_core::Value::Integer(1), turso_core::Value::Null],
+            &["id", "label"],
+        );
+
+        assert_eq!(row.columns().len(), 2);
+        assert_eq!(row.column_name(0).unwrap(), "id");
+        assert_eq!(row.column_name(1).unwrap(), "label");
+        assert!(matches!(
+            row.column_name(2),
+            Err(Error::InvalidColumnIndex(2))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_missing_option_field_is_none() {
+        #[derive(serde::Deserialize)]
+        struct Partial {
+            id: i64,
+            nickname: Option<String>,
+        }
+
+        let row = row(vec![turso_core::Value::Integer(1)], &["id"]);
+        let parsed: Partial = row.deserialize().unwrap();
+        assert_eq!(parsed.id, 1);
+        assert_eq!(parsed.nickname, None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_missing_required_field_errors() {
+        #[derive(serde::Deserialize)]
+        struct Required {
+            #[allow(dead_code)]
+            id: i64,
+            #[allow(dead_code)]
+            name: String,
+        }
+
+        let row = row(vec![turso_core::Value::Integer(1)], &["id"]);
+        let result: Result<Required> = row.deserialize();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn finalize_batch_non_exhausted_batch_is_not_done() {
+        let row = row(vec![turso_core::Value::Integer(1)], &["id"]);
+        let (item, done) = finalize_batch(vec![row], false);
+        assert!(item.is_some());
+        assert!(!done);
+    }
+
+    #[test]
+    fn finalize_batch_exhausted_short_batch_is_done() {
+        let row = row(vec![turso_core::Value::Integer(1)], &["id"]);
+        let (item, done) = finalize_batch(vec![row], true);
+        assert!(item.is_some());
+        assert!(done);
+    }
+
+    #[test]
+    fn finalize_batch_exhausted_empty_batch_yields_none() {
+        let (item, done) = finalize_batch(Vec::new(), true);
+        assert!(item.is_none());
+        assert!(done);
+    }
+
+    #[test]
+    fn finalize_batch_non_exhausted_empty_batch_yields_none_but_not_done() {
+        let (item, done) = finalize_batch(Vec::new(), false);
+        assert!(item.is_none());
+        assert!(!done);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_blob_into_string_fails_cleanly() {
+        #[derive(serde::Deserialize)]
+        struct Text {
+            #[allow(dead_code)]
+            value: String,
+        }
+
+        let row = row(vec![turso_core::Value::Blob(vec![1, 2, 3])], &["value"]);
+        let result: Result<Text> = row.deserialize();
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_blob_into_char_fails_cleanly() {
+        #[derive(serde::Deserialize)]
+        struct Letter {
+            #[allow(dead_code)]
+            value: char,
+        }
+
+        let row = row(vec![turso_core::Value::Blob(vec![1])], &["value"]);
+        let result: Result<Letter> = row.deserialize();
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_blob_into_vec_u8_round_trips() {
+        #[derive(serde::Deserialize)]
+        struct Bytes {
+            value: Vec<u8>,
+        }
+
+        let row = row(vec![turso_core::Value::Blob(vec![1, 2, 3])], &["value"]);
+        let parsed: Bytes = row.deserialize().unwrap();
+        assert_eq!(parsed.value, vec![1, 2, 3]);
+    }
+}